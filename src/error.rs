@@ -93,32 +93,399 @@ pub enum CudaError {
     StreamCaptureWrongThread = 908,
     Timeout = 909,
     GraphExecUpdateFailure = 910,
-    UnknownError = 999,
+
+    StubLibrary = 34,
+    DeviceUnavailable = 46,
+    DeviceNotLicensed = 102,
+    JitCompilerNotFound = 221,
+    UnsupportedPtxVersion = 222,
+    JitCompilationDisabled = 223,
+    UnsupportedExecAffinity = 224,
+    IllegalState = 401,
 
     // RustaCUDA errors
     InvalidMemoryAllocation = 100_100,
 
+    /// A driver status code with no corresponding named variant, carrying the raw `CUresult`
+    /// value so it isn't silently discarded. This keeps the enum forward-compatible with driver
+    /// releases that add error codes RustaCUDA hasn't caught up with yet. When built by
+    /// `CudaError::from_runtime`, it carries a raw runtime API code instead.
+    Unknown(u32),
+
     #[doc(hidden)]
     __Nonexhaustive,
 }
-impl fmt::Display for CudaError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+/// A coarse grouping of [`CudaError`] variants, for callers that want to branch on the kind of
+/// failure without enumerating dozens of individual variants.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ErrorCategory {
+    /// Allocation, mapping or registration of host/device memory failed.
+    Memory,
+    /// A kernel launch failed or was configured incorrectly.
+    Launch,
+    /// The CUDA context is missing, already current, destroyed, or otherwise unusable.
+    Context,
+    /// The driver or a device could not be initialized.
+    Initialization,
+    /// A stream-capture operation was used incorrectly.
+    Capture,
+    /// Peer-to-peer access between devices was misused.
+    PeerAccess,
+    /// The driver rejected the call for a reason unrelated to the categories above (invalid
+    /// arguments, unsupported operation, profiler misuse, ...).
+    Driver,
+    /// The device faulted while executing (illegal or misaligned memory access, illegal
+    /// instruction, device-side assert, uncorrectable ECC or NVLink error, ...); such failures
+    /// are unrecoverable.
+    Hardware,
+    /// A raw code carried by [`CudaError::Unknown`], which this enum doesn't otherwise
+    /// categorize.
+    Other,
+}
+
+impl CudaError {
+    /// Returns `true` if this error is "sticky": a severe, context-corrupting failure (illegal
+    /// memory access, ECC uncorrectable error, hardware stack error, assert, launch failure,
+    /// ...) that the driver will keep reporting from every subsequent call made on the current
+    /// context, rather than a transient per-call error that is simply returned and forgotten.
+    ///
+    /// A sticky error means the context is unrecoverable: it must be destroyed and recreated,
+    /// as opposed to non-sticky errors where retrying the same operation (or a different one)
+    /// on the same context is still meaningful.
+    pub fn is_sticky(&self) -> bool {
+        matches!(
+            self,
+            CudaError::IllegalAddress
+                | CudaError::LaunchFailed
+                | CudaError::HardwareStackError
+                | CudaError::IllegalInstruction
+                | CudaError::MisalignedAddress
+                | CudaError::InvalidAddressSpace
+                | CudaError::InvalidProgramCounter
+                | CudaError::AssertError
+                | CudaError::EccUncorrectable
+                | CudaError::NvlinkUncorrectable
+                | CudaError::ContextIsDestroyed
+        )
+    }
+
+    /// Groups this error into a coarse [`ErrorCategory`].
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            CudaError::OutOfMemory
+            | CudaError::MapFailed
+            | CudaError::UnmapFailed
+            | CudaError::ArrayIsMapped
+            | CudaError::AlreadyMapped
+            | CudaError::AlreadyAcquired
+            | CudaError::NotMapped
+            | CudaError::NotMappedAsArray
+            | CudaError::NotMappedAsPointer
+            | CudaError::HostMemoryAlreadyRegistered
+            | CudaError::HostMemoryNotRegistered
+            | CudaError::InvalidMemoryAllocation => ErrorCategory::Memory,
+
+            CudaError::InvalidImage
+            | CudaError::InvalidPtx
+            | CudaError::NoBinaryForGpu
+            | CudaError::LaunchOutOfResources
+            | CudaError::LaunchTimeout
+            | CudaError::LaunchIncompatibleTexturing
+            | CudaError::LaunchFailed
+            | CudaError::JitCompilerNotFound
+            | CudaError::UnsupportedPtxVersion
+            | CudaError::JitCompilationDisabled
+            | CudaError::UnsupportedExecAffinity
+            | CudaError::GraphExecUpdateFailure => ErrorCategory::Launch,
+
+            CudaError::InvalidContext
+            | CudaError::ContextAlreadyCurrent
+            | CudaError::ContextAlreadyInUse
+            | CudaError::PrimaryContextActive
+            | CudaError::ContextIsDestroyed
+            | CudaError::InvalidHandle
+            | CudaError::IllegalState
+            | CudaError::InvalidGraphicsContext => ErrorCategory::Context,
+
+            CudaError::NotInitialized
+            | CudaError::Deinitialized
+            | CudaError::NoDevice
+            | CudaError::InvalidDevice
+            | CudaError::DeviceUnavailable
+            | CudaError::DeviceNotLicensed
+            | CudaError::StubLibrary
+            | CudaError::SystemNotReady
+            | CudaError::SystemDriverMismatch
+            | CudaError::CompatNotSupportedOnDevice => ErrorCategory::Initialization,
+
+            CudaError::StreamCaptureUnsupported
+            | CudaError::StreamCaptureInvalidated
+            | CudaError::StreamCaptureMerge
+            | CudaError::StreamCaptureUnmatched
+            | CudaError::StreamCaptureUnjoined
+            | CudaError::StreamCaptureIsolation
+            | CudaError::StreamCaptureImplicit
+            | CudaError::CapturedEvent
+            | CudaError::StreamCaptureWrongThread => ErrorCategory::Capture,
+
+            CudaError::PeerAccessUnsupported
+            | CudaError::PeerAccessAlreadyEnabled
+            | CudaError::PeerAccessNotEnabled
+            | CudaError::TooManyPeers => ErrorCategory::PeerAccess,
+
+            CudaError::IllegalAddress
+            | CudaError::EccUncorrectable
+            | CudaError::NvlinkUncorrectable
+            | CudaError::HardwareStackError
+            | CudaError::IllegalInstruction
+            | CudaError::MisalignedAddress
+            | CudaError::InvalidAddressSpace
+            | CudaError::InvalidProgramCounter
+            | CudaError::AssertError => ErrorCategory::Hardware,
+
+            CudaError::InvalidValue
+            | CudaError::ProfilerDisabled
+            | CudaError::ProfilerNotInitialized
+            | CudaError::ProfilerAlreadyStarted
+            | CudaError::ProfilerAlreadyStopped
+            | CudaError::UnsupportedLimit
+            | CudaError::InvalidSouce
+            | CudaError::FileNotFound
+            | CudaError::SharedObjectSymbolNotFound
+            | CudaError::SharedObjectInitFailed
+            | CudaError::OperatingSystemError
+            | CudaError::NotFound
+            | CudaError::NotReady
+            | CudaError::NotPermitted
+            | CudaError::NotSupported
+            | CudaError::Timeout => ErrorCategory::Driver,
+
+            CudaError::Unknown(_) | CudaError::__Nonexhaustive => ErrorCategory::Other,
+        }
+    }
+
+    /// Returns `true` if this failure is transient and the operation that produced it might
+    /// succeed if retried (possibly after a backoff, or after freeing up memory in the case of
+    /// [`CudaError::OutOfMemory`]). Returns `false` for permanent or context-corrupting errors,
+    /// which retrying cannot fix.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            CudaError::NotReady
+                | CudaError::OutOfMemory
+                | CudaError::SystemNotReady
+                | CudaError::Timeout
+        )
+    }
+
+    /// The stable, log-grep-able symbolic name of this error, e.g.
+    /// `"CUDA_ERROR_ILLEGAL_ADDRESS"`, backed by `cuGetErrorName`.
+    pub fn name(&self) -> &'static str {
         match *self {
-            CudaError::InvalidMemoryAllocation => write!(f, "Invalid memory allocation"),
-            CudaError::__Nonexhaustive => write!(f, "__Nonexhaustive"),
-            other if (other as u32) <= 999 => {
-                let value = other as u32;
+            CudaError::InvalidMemoryAllocation => "RUSTACUDA_ERROR_INVALID_MEMORY_ALLOCATION",
+            CudaError::__Nonexhaustive => "__Nonexhaustive",
+            other => {
+                let code = other.raw_code();
                 let mut ptr: *const c_char = ptr::null();
                 unsafe {
-                    cuda::cuGetErrorString(mem::transmute(value), &mut ptr as *mut *const c_char)
+                    match cuda::cuGetErrorName(mem::transmute(code), &mut ptr as *mut *const c_char)
                         .to_result()
-                        .map_err(|_| fmt::Error)?;
-                    let cstr = CStr::from_ptr(ptr);
-                    write!(f, "{:?}", cstr)
+                    {
+                        Ok(()) => CStr::from_ptr(ptr).to_str().unwrap_or("CUDA_ERROR_UNKNOWN"),
+                        Err(_) => "CUDA_ERROR_UNKNOWN",
+                    }
+                }
+            }
+        }
+    }
+
+    /// The raw numeric `CUresult` code this error corresponds to. Unlike a plain `as u32` cast,
+    /// this works even though [`CudaError::Unknown`] carries data and so can no longer be cast
+    /// directly.
+    fn raw_code(&self) -> u32 {
+        match *self {
+            CudaError::InvalidValue => 1,
+            CudaError::OutOfMemory => 2,
+            CudaError::NotInitialized => 3,
+            CudaError::Deinitialized => 4,
+            CudaError::ProfilerDisabled => 5,
+            CudaError::ProfilerNotInitialized => 6,
+            CudaError::ProfilerAlreadyStarted => 7,
+            CudaError::ProfilerAlreadyStopped => 8,
+            CudaError::StubLibrary => 34,
+            CudaError::DeviceUnavailable => 46,
+            CudaError::DeviceNotLicensed => 102,
+            CudaError::NoDevice => 100,
+            CudaError::InvalidDevice => 101,
+            CudaError::InvalidImage => 200,
+            CudaError::InvalidContext => 201,
+            CudaError::ContextAlreadyCurrent => 202,
+            CudaError::MapFailed => 205,
+            CudaError::UnmapFailed => 206,
+            CudaError::ArrayIsMapped => 207,
+            CudaError::AlreadyMapped => 208,
+            CudaError::NoBinaryForGpu => 209,
+            CudaError::AlreadyAcquired => 210,
+            CudaError::NotMapped => 211,
+            CudaError::NotMappedAsArray => 212,
+            CudaError::NotMappedAsPointer => 213,
+            CudaError::EccUncorrectable => 214,
+            CudaError::UnsupportedLimit => 215,
+            CudaError::ContextAlreadyInUse => 216,
+            CudaError::PeerAccessUnsupported => 217,
+            CudaError::InvalidPtx => 218,
+            CudaError::InvalidGraphicsContext => 219,
+            CudaError::NvlinkUncorrectable => 220,
+            CudaError::JitCompilerNotFound => 221,
+            CudaError::UnsupportedPtxVersion => 222,
+            CudaError::JitCompilationDisabled => 223,
+            CudaError::UnsupportedExecAffinity => 224,
+            CudaError::InvalidSouce => 300,
+            CudaError::FileNotFound => 301,
+            CudaError::SharedObjectSymbolNotFound => 302,
+            CudaError::SharedObjectInitFailed => 303,
+            CudaError::OperatingSystemError => 304,
+            CudaError::InvalidHandle => 400,
+            CudaError::IllegalState => 401,
+            CudaError::NotFound => 500,
+            CudaError::NotReady => 600,
+            CudaError::IllegalAddress => 700,
+            CudaError::LaunchOutOfResources => 701,
+            CudaError::LaunchTimeout => 702,
+            CudaError::LaunchIncompatibleTexturing => 703,
+            CudaError::PeerAccessAlreadyEnabled => 704,
+            CudaError::PeerAccessNotEnabled => 705,
+            CudaError::PrimaryContextActive => 708,
+            CudaError::ContextIsDestroyed => 709,
+            CudaError::AssertError => 710,
+            CudaError::TooManyPeers => 711,
+            CudaError::HostMemoryAlreadyRegistered => 712,
+            CudaError::HostMemoryNotRegistered => 713,
+            CudaError::HardwareStackError => 714,
+            CudaError::IllegalInstruction => 715,
+            CudaError::MisalignedAddress => 716,
+            CudaError::InvalidAddressSpace => 717,
+            CudaError::InvalidProgramCounter => 718,
+            CudaError::LaunchFailed => 719,
+            CudaError::NotPermitted => 800,
+            CudaError::NotSupported => 801,
+            CudaError::SystemNotReady => 802,
+            CudaError::SystemDriverMismatch => 803,
+            CudaError::CompatNotSupportedOnDevice => 804,
+            CudaError::StreamCaptureUnsupported => 900,
+            CudaError::StreamCaptureInvalidated => 901,
+            CudaError::StreamCaptureMerge => 902,
+            CudaError::StreamCaptureUnmatched => 903,
+            CudaError::StreamCaptureUnjoined => 904,
+            CudaError::StreamCaptureIsolation => 905,
+            CudaError::StreamCaptureImplicit => 906,
+            CudaError::CapturedEvent => 907,
+            CudaError::StreamCaptureWrongThread => 908,
+            CudaError::Timeout => 909,
+            CudaError::GraphExecUpdateFailure => 910,
+            CudaError::InvalidMemoryAllocation => 100_100,
+            CudaError::Unknown(code) => code,
+            CudaError::__Nonexhaustive => unreachable!(),
+        }
+    }
+}
+
+#[cfg(feature = "runtime_errors")]
+impl CudaError {
+    /// Builds a [`CudaError`] from a raw CUDA **runtime** API error code (the `cudaError_t` of
+    /// `cuda_runtime.h`), as opposed to the driver API's `CUresult` that the rest of this module
+    /// models. The two enumerations are numbered independently and mostly disagree even where
+    /// the numbers overlap: runtime code `1` is `cudaErrorMissingConfiguration`, `4` is
+    /// `cudaErrorLaunchFailure`, `5` is `cudaErrorPriorLaunchFailure`, `6` is
+    /// `cudaErrorLaunchTimeout`, `7` is `cudaErrorLaunchOutOfResources`, `8` is
+    /// `cudaErrorInvalidDeviceFunction`, `9` is `cudaErrorInvalidConfiguration`, `13` is
+    /// `cudaErrorInvalidSymbol`, `35` is `cudaErrorInsufficientDriver` — none of which share a
+    /// meaning with the driver code of the same number. Only `2` (`cudaErrorMemoryAllocation`)
+    /// genuinely coincides with its driver-API counterpart ([`CudaError::OutOfMemory`]).
+    ///
+    /// Every other code, including ones that happen to fall in a range the driver API also
+    /// uses, is treated as having no known driver-API analogue and becomes
+    /// [`CudaError::Unknown`], carrying the raw runtime code so it isn't silently discarded. A
+    /// runtime code like `9` (`cudaErrorInvalidConfiguration`) happens to fall in the numeric
+    /// range of valid `CUresult`s without being one; [`Display`] and [`CudaError::name`] handle
+    /// that gracefully (falling back to an "unknown" message) rather than treating the driver's
+    /// rejection of the lookup as a formatting failure.
+    pub fn from_runtime(code: u32) -> CudaError {
+        match code {
+            2 => CudaError::OutOfMemory,
+            other => CudaError::Unknown(other),
+        }
+    }
+
+    /// Best-effort inverse of [`CudaError::from_runtime`]: returns the raw CUDA runtime API
+    /// error code that corresponds to this error, if the runtime API has a genuinely equivalent
+    /// code (see [`CudaError::from_runtime`]). Any [`CudaError::Unknown`] returns the raw code it
+    /// carries unchanged, whether it came from `from_runtime` or from a driver call.
+    pub fn to_runtime(&self) -> Option<u32> {
+        match *self {
+            CudaError::OutOfMemory => Some(2),
+            CudaError::Unknown(code) => Some(code),
+            _ => None,
+        }
+    }
+}
+
+/// Classifies a raw driver status code into a [`CudaResult`], reusing the same mapping as
+/// [`ToResult::to_result`]. Useful for crates that obtain a raw `CUresult` from some other CUDA
+/// binding and want RustaCUDA's error classification without duplicating the match table
+/// themselves.
+pub fn result_from_raw(raw: u32) -> CudaResult<()> {
+    let status: cudaError_t = unsafe { mem::transmute(raw) };
+    status.to_result()
+}
+
+/// Checks whether a previous asynchronous operation on the current context (a kernel launch, an
+/// async memcopy, ...) has left it in an error state.
+///
+/// The driver only reports the error from the *last* failing call; if an asynchronous operation
+/// fails, that failure isn't observed until some later call touches the context. This forces
+/// that outstanding error to surface by synchronizing the context (`cuCtxSynchronize`), which
+/// blocks until all outstanding work completes — there is no non-blocking query for this in the
+/// driver API. Useful before issuing new work: if the returned error
+/// [is sticky](CudaError::is_sticky), the context is corrupt and must be destroyed and rebuilt
+/// rather than reused. A non-sticky outstanding error is consumed by the synchronization itself,
+/// so it won't be reported again by a later call; there is no separate "reset" to perform, since
+/// a sticky error can't be cleared this way (or any other) short of destroying the context.
+pub fn peek_outstanding() -> CudaResult<()> {
+    unsafe { cuda::cuCtxSynchronize().to_result() }
+}
+
+impl fmt::Display for CudaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CudaError::InvalidMemoryAllocation => write!(
+                f,
+                "{} ({}): invalid memory allocation",
+                self.name(),
+                self.raw_code()
+            ),
+            CudaError::__Nonexhaustive => write!(f, "__Nonexhaustive"),
+            other => {
+                let code = other.raw_code();
+                let description = if code <= 999 {
+                    let mut ptr: *const c_char = ptr::null();
+                    unsafe {
+                        match cuda::cuGetErrorString(mem::transmute(code), &mut ptr as *mut *const c_char)
+                            .to_result()
+                        {
+                            Ok(()) => Some(CStr::from_ptr(ptr).to_string_lossy().into_owned()),
+                            Err(_) => None,
+                        }
+                    }
+                } else {
+                    None
+                };
+                match description {
+                    Some(description) => write!(f, "{} ({}): {}", other.name(), code, description),
+                    None => write!(f, "{} ({}): unknown CUDA error", other.name(), code),
                 }
             }
-            // This shouldn't happen
-            _ => write!(f, "Unknown error"),
         }
     }
 }
@@ -218,7 +585,37 @@ impl ToResult for cudaError_t {
             cudaError_t::CUDA_ERROR_LAUNCH_FAILED => Err(CudaError::LaunchFailed),
             cudaError_t::CUDA_ERROR_NOT_PERMITTED => Err(CudaError::NotPermitted),
             cudaError_t::CUDA_ERROR_NOT_SUPPORTED => Err(CudaError::NotSupported),
-            _ => Err(CudaError::UnknownError),
+            cudaError_t::CUDA_ERROR_GRAPH_EXEC_UPDATE_FAILURE => {
+                Err(CudaError::GraphExecUpdateFailure)
+            }
+            cudaError_t::CUDA_ERROR_STUB_LIBRARY => Err(CudaError::StubLibrary),
+            cudaError_t::CUDA_ERROR_DEVICE_UNAVAILABLE => Err(CudaError::DeviceUnavailable),
+            cudaError_t::CUDA_ERROR_DEVICE_NOT_LICENSED => Err(CudaError::DeviceNotLicensed),
+            cudaError_t::CUDA_ERROR_JIT_COMPILER_NOT_FOUND => Err(CudaError::JitCompilerNotFound),
+            cudaError_t::CUDA_ERROR_UNSUPPORTED_PTX_VERSION => {
+                Err(CudaError::UnsupportedPtxVersion)
+            }
+            cudaError_t::CUDA_ERROR_JIT_COMPILATION_DISABLED => {
+                Err(CudaError::JitCompilationDisabled)
+            }
+            cudaError_t::CUDA_ERROR_UNSUPPORTED_EXEC_AFFINITY => {
+                Err(CudaError::UnsupportedExecAffinity)
+            }
+            cudaError_t::CUDA_ERROR_ILLEGAL_STATE => Err(CudaError::IllegalState),
+            cudaError_t::CUDA_ERROR_SYSTEM_NOT_READY => Err(CudaError::SystemNotReady),
+            cudaError_t::CUDA_ERROR_SYSTEM_DRIVER_MISMATCH => Err(CudaError::SystemDriverMismatch),
+            cudaError_t::CUDA_ERROR_COMPAT_NOT_SUPPORTED_ON_DEVICE => Err(CudaError::CompatNotSupportedOnDevice),
+            cudaError_t::CUDA_ERROR_STREAM_CAPTURE_UNSUPPORTED => Err(CudaError::StreamCaptureUnsupported),
+            cudaError_t::CUDA_ERROR_STREAM_CAPTURE_INVALIDATED => Err(CudaError::StreamCaptureInvalidated),
+            cudaError_t::CUDA_ERROR_STREAM_CAPTURE_MERGE => Err(CudaError::StreamCaptureMerge),
+            cudaError_t::CUDA_ERROR_STREAM_CAPTURE_UNMATCHED => Err(CudaError::StreamCaptureUnmatched),
+            cudaError_t::CUDA_ERROR_STREAM_CAPTURE_UNJOINED => Err(CudaError::StreamCaptureUnjoined),
+            cudaError_t::CUDA_ERROR_STREAM_CAPTURE_ISOLATION => Err(CudaError::StreamCaptureIsolation),
+            cudaError_t::CUDA_ERROR_STREAM_CAPTURE_IMPLICIT => Err(CudaError::StreamCaptureImplicit),
+            cudaError_t::CUDA_ERROR_CAPTURED_EVENT => Err(CudaError::CapturedEvent),
+            cudaError_t::CUDA_ERROR_STREAM_CAPTURE_WRONG_THREAD => Err(CudaError::StreamCaptureWrongThread),
+            cudaError_t::CUDA_ERROR_TIMEOUT => Err(CudaError::Timeout),
+            other => Err(CudaError::Unknown(other as u32)),
         }
     }
 }